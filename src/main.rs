@@ -1,8 +1,16 @@
-use clap::Parser;
-use reqwest::blocking::Client;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
 use std::error::Error;
+use std::sync::Arc;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use futures::future::join_all;
+use regex::Regex;
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::Semaphore;
+use chrono::Local;
+use tracing::{debug, info};
+use tracing_subscriber::filter::LevelFilter;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct CollectionInfo {
@@ -20,52 +28,425 @@ impl CollectionInfo {
         // A collection is considered healthy if status is "green" and there are no errors
         self.status.as_ref().map(|s| s == "green").unwrap_or(false) && self.error.is_none()
     }
+
+    /// Whether this collection counts as a monitoring failure.
+    ///
+    /// A missing status, an error, or a `red` status is always a failure; a
+    /// `yellow` status is only treated as one when `fail_on_yellow` is set.
+    fn is_failure(&self, fail_on_yellow: bool) -> bool {
+        if self.error.is_some() {
+            return true;
+        }
+        match self.status.as_deref() {
+            Some("green") => false,
+            Some("yellow") => fail_on_yellow,
+            _ => true,
+        }
+    }
+}
+
+/// Connection settings for talking to a Qdrant instance.
+///
+/// Holds the base URL and an optional API key; when a key is present it is
+/// attached as the `api-key` header on every outgoing request.
+struct Qdrant {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl Qdrant {
+    fn new(base_url: String, api_key: Option<String>) -> Self {
+        Qdrant {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+        }
+    }
+
+    /// Attach the `api-key` header to a request builder when a key is set.
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match self.api_key {
+            Some(ref key) => request.header("api-key", key),
+            None => request,
+        }
+    }
+
+    /// Build a GET request to `{base_url}{path}`, authorizing it if a key is set.
+    fn get(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.get(format!("{}{}", self.base_url, path)))
+    }
+
+    /// Build a PUT request to `{base_url}{path}`, authorizing it if a key is set.
+    fn put(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.put(format!("{}{}", self.base_url, path)))
+    }
+
+    /// Build a DELETE request to `{base_url}{path}`, authorizing it if a key is set.
+    fn delete(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.delete(format!("{}{}", self.base_url, path)))
+    }
+
+    /// Build a POST request to `{base_url}{path}`, authorizing it if a key is set.
+    fn post(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.post(format!("{}{}", self.base_url, path)))
+    }
+}
+
+/// A filter over collection names, applied before any detail requests are
+/// issued so we only fetch the collections the user actually cares about.
+///
+/// A name passes when it satisfies *every* configured predicate: it contains
+/// the `name` substring, it matches the `pattern`, and it appears in the `ids`
+/// allow-list. Fields left `None` are not consulted.
+#[derive(Debug, Default)]
+struct CollectionsQuery {
+    name: Option<String>,
+    ids: Option<Vec<String>>,
+    pattern: Option<Regex>,
+}
+
+impl CollectionsQuery {
+    fn new(name: Option<String>, ids: Vec<String>, pattern: Option<Regex>) -> Self {
+        CollectionsQuery {
+            name,
+            ids: if ids.is_empty() { None } else { Some(ids) },
+            pattern,
+        }
+    }
+
+    /// Returns true when `name` satisfies every configured predicate.
+    fn matches(&self, name: &str) -> bool {
+        if let Some(ref needle) = self.name {
+            if !name.contains(needle.as_str()) {
+                return false;
+            }
+        }
+        if let Some(ref ids) = self.ids {
+            if !ids.iter().any(|id| id == name) {
+                return false;
+            }
+        }
+        if let Some(ref pattern) = self.pattern {
+            if !pattern.is_match(name) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Whether collections that hold no points are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+enum EmptyFilter {
+    /// Keep collections that hold no points (the default)
+    #[default]
+    Include,
+    /// Drop collections whose point count is known to be zero
+    Exclude,
+}
+
+/// How `CollectionInfo` records are rendered on stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Compact single-line JSON array
+    Json,
+    /// Indented, human-readable JSON array
+    Pretty,
+    /// Aligned columns (name/status/points/vectors/indexed)
+    Table,
+    /// One header row plus one comma-separated row per collection
+    Csv,
+    /// One compact JSON object per line
+    Ndjson,
+}
+
+/// Render a slice of collections to stdout in the requested format.
+fn print_collections(collections: &[&CollectionInfo], format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(collections)?),
+        OutputFormat::Pretty => println!("{}", serde_json::to_string_pretty(collections)?),
+        OutputFormat::Ndjson => {
+            for collection in collections {
+                println!("{}", serde_json::to_string(collection)?);
+            }
+        }
+        OutputFormat::Csv => {
+            println!("name,status,points,vectors,indexed");
+            for c in collections {
+                println!(
+                    "{},{},{},{},{}",
+                    csv_field(&c.name),
+                    csv_field(c.status.as_deref().unwrap_or("")),
+                    opt_count(c.points_count),
+                    opt_count(c.vectors_count),
+                    opt_count(c.indexed_vectors_count),
+                );
+            }
+        }
+        OutputFormat::Table => {
+            let header = ["NAME", "STATUS", "POINTS", "VECTORS", "INDEXED"];
+            let mut rows: Vec<[String; 5]> = vec![header.map(String::from)];
+            for c in collections {
+                rows.push([
+                    c.name.clone(),
+                    c.status.clone().unwrap_or_default(),
+                    opt_count(c.points_count),
+                    opt_count(c.vectors_count),
+                    opt_count(c.indexed_vectors_count),
+                ]);
+            }
+
+            // Size every column to its widest cell before printing.
+            let mut widths = [0usize; 5];
+            for row in &rows {
+                for (i, cell) in row.iter().enumerate() {
+                    widths[i] = widths[i].max(cell.len());
+                }
+            }
+            for row in &rows {
+                let line: Vec<String> = row
+                    .iter()
+                    .enumerate()
+                    .map(|(i, cell)| format!("{:<width$}", cell, width = widths[i]))
+                    .collect();
+                println!("{}", line.join("  ").trim_end());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Format an optional count for tabular/CSV output, using `-` when absent.
+fn opt_count(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+/// Quote a CSV field per RFC 4180 when it holds a comma, quote, or newline,
+/// doubling any embedded quotes so downstream columns don't shift.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 #[derive(Parser, Debug)]
 #[command(name = "qdrant-collection-cli")]
-#[command(about = "Fetch and display Qdrant collections information")]
+#[command(about = "Manage and inspect Qdrant collections")]
 struct Args {
-    /// Filter output by health status: healthy or unhealthy
-    #[arg(long, value_name = "TYPE")]
-    only: Option<String>,
-    
-    /// Enable verbose output with additional information
-    #[arg(long)]
-    verbose: bool,
+    /// Base URL of the Qdrant instance
+    #[arg(long, value_name = "URL", env = "QDRANT_URL", default_value = "http://localhost:6333")]
+    url: String,
+
+    /// API key used to authenticate against secured deployments
+    #[arg(long, value_name = "KEY", env = "QDRANT_API_KEY")]
+    api_key: Option<String>,
+
+    /// Output format for collection records
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    output: OutputFormat,
+
+    /// Increase log verbosity; repeat for more detail (-v info, -vv debug)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List collections and their details
+    List {
+        /// Filter output by health status: healthy or unhealthy
+        #[arg(long, value_name = "TYPE")]
+        only: Option<String>,
+
+        /// Maximum number of per-collection detail requests in flight at once
+        #[arg(long, default_value_t = 8)]
+        concurrency: usize,
+
+        /// Only inspect collections whose name contains this substring
+        #[arg(long, value_name = "SUBSTRING")]
+        name: Option<String>,
+
+        /// Only inspect collections whose name matches this regular expression
+        #[arg(long, value_name = "PATTERN")]
+        regex: Option<String>,
+
+        /// Inspect only the named collection; may be repeated
+        #[arg(long = "id", value_name = "NAME")]
+        ids: Vec<String>,
+
+        /// Whether to include or exclude collections that hold no points
+        #[arg(long = "empty", value_enum, default_value_t = EmptyFilter::Include)]
+        empty: EmptyFilter,
+
+        /// Exit non-zero (by unhealthy count, capped at 255) instead of printing
+        /// data. A `yellow` status counts as healthy unless --fail-on-yellow is set
+        #[arg(long)]
+        check: bool,
+
+        /// Re-poll every N seconds, printing a timestamped status line each cycle
+        #[arg(long, value_name = "SECONDS")]
+        watch: Option<u64>,
+
+        /// Treat yellow (not-green-but-not-red) statuses as failures for --check
+        #[arg(long)]
+        fail_on_yellow: bool,
+    },
+    /// Show details for a single collection
+    Info {
+        /// Name of the collection to inspect
+        name: String,
+    },
+    /// Create a new collection
+    Create {
+        /// Name of the collection to create
+        name: String,
+
+        /// Dimensionality of the stored vectors
+        #[arg(long, default_value_t = 4)]
+        size: u64,
+
+        /// Distance metric used for the collection
+        #[arg(long, default_value = "Cosine")]
+        distance: String,
+    },
+    /// Delete an existing collection
+    Delete {
+        /// Name of the collection to delete
+        name: String,
+    },
+    /// Create a snapshot of a collection
+    Snapshot {
+        /// Name of the collection to snapshot
+        name: String,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
-    
-    // Validate the --only argument if provided
-    if let Some(ref filter) = args.only {
-        if filter != "healthy" && filter != "unhealthy" {
-            return Err(format!("Invalid value for --only: '{}'. Must be 'healthy' or 'unhealthy'", filter).into());
+
+    // Structured diagnostics go to stderr so stdout stays clean for data.
+    let level = match args.verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        _ => LevelFilter::DEBUG,
+    };
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_max_level(level)
+        .with_target(false)
+        .init();
+
+    // Create an authorized client for the configured instance
+    let qdrant = Qdrant::new(args.url.clone(), args.api_key.clone());
+
+    match args.command {
+        Command::List { ref only, concurrency, ref name, ref regex, ref ids, empty, check, watch, fail_on_yellow } => {
+            let pattern = match regex {
+                Some(p) => Some(Regex::new(p)?),
+                None => None,
+            };
+            let query = CollectionsQuery::new(name.clone(), ids.clone(), pattern);
+            let monitor = Monitor { check, watch, fail_on_yellow };
+            run_list(&qdrant, args.output, only.as_deref(), concurrency, query, empty == EmptyFilter::Exclude, monitor).await
         }
+        Command::Info { ref name } => run_info(&qdrant, args.output, name).await,
+        Command::Create { ref name, size, ref distance } => {
+            run_create(&qdrant, name, size, distance).await
+        }
+        Command::Delete { ref name } => run_delete(&qdrant, name).await,
+        Command::Snapshot { ref name } => run_snapshot(&qdrant, name).await,
     }
-    
-    // Create an HTTP client
-    let client = Client::new();
-    
-    // Make a GET request to the Qdrant collections endpoint
-    let url = "http://localhost:6333/collections";
-    if args.verbose {
-        println!("Calling endpoint: {}", url);
-    }
-    
-    let response = client.get(url).send()?;
-    
-    // Check if the request was successful
-    let status = response.status();
-    if args.verbose {
-        println!("Response status: {}", status);
+}
+
+/// Fetch the details of a single collection, capturing any failure on the
+/// returned `CollectionInfo` rather than propagating it.
+async fn fetch_collection(qdrant: &Qdrant, name: &str) -> CollectionInfo {
+    let collection_path = format!("/collections/{}", name);
+
+    let mut info = CollectionInfo {
+        name: name.to_string(),
+        status: None,
+        vectors_count: None,
+        points_count: None,
+        indexed_vectors_count: None,
+        vector_config: None,
+        error: None,
+    };
+
+    debug!(url = %format!("{}{}", qdrant.base_url, collection_path), "fetching collection details");
+    match qdrant.get(&collection_path).send().await {
+        Ok(coll_response) => {
+            debug!(collection = name, status = %coll_response.status(), "collection detail response");
+            if coll_response.status().is_success() {
+                match coll_response.json::<Value>().await {
+                    Ok(coll_data) => {
+                        // Extract useful information from the collection details
+                        if let Some(result) = coll_data.get("result") {
+                            info.status = result.get("status")
+                                .and_then(|v| v.as_str())
+                                .map(|s| s.to_string());
+
+                            info.vectors_count = result.get("vectors_count")
+                                .and_then(|v| v.as_u64());
+
+                            info.points_count = result.get("points_count")
+                                .and_then(|v| v.as_u64());
+
+                            info.indexed_vectors_count = result.get("indexed_vectors_count")
+                                .and_then(|v| v.as_u64());
+
+                            if let Some(config) = result.get("config") {
+                                if let Some(params) = config.get("params") {
+                                    info.vector_config = params.get("vectors").cloned();
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        info.error = Some(format!("Error parsing collection details: {}", e));
+                    }
+                }
+            } else {
+                info.error = Some(format!("Failed to get collection details (status: {})", coll_response.status()));
+            }
+        }
+        Err(e) => {
+            info.error = Some(format!("Failed to fetch collection details: {}", e));
+        }
     }
-    
+
+    info
+}
+
+/// Monitoring options for the `List` command that turn the inspector into a
+/// health probe rather than a one-shot report.
+struct Monitor {
+    check: bool,
+    watch: Option<u64>,
+    fail_on_yellow: bool,
+}
+
+/// Fetch every queried collection's details concurrently, dropping empty ones
+/// when requested. Shared by both the report and monitoring paths.
+async fn collect_collections(qdrant: &Qdrant, concurrency: usize, query: &CollectionsQuery, exclude_empty: bool) -> Result<Vec<CollectionInfo>, Box<dyn Error>> {
+    // Make a GET request to the Qdrant collections endpoint
+    debug!(url = %format!("{}/collections", qdrant.base_url), "listing collections");
+
+    let response = qdrant.get("/collections").send().await?;
+    debug!(status = %response.status(), "collections list response");
+
     // Parse the JSON response
-    let body: Value = response.json()?;
-    
-    // Extract collection names from result.collections[].name
+    let body: Value = response.json().await?;
+
+    // Extract collection names from result.collections[].name, keeping only
+    // those that satisfy the name/regex/id query.
     let collection_names: Vec<String> = body["result"]["collections"]
         .as_array()
         .ok_or("Expected 'result.collections' to be an array")?
@@ -73,92 +454,210 @@ fn main() -> Result<(), Box<dyn Error>> {
         .filter_map(|collection| {
             collection["name"].as_str().map(|s| s.to_string())
         })
+        .filter(|name| query.matches(name))
         .collect();
-    
-    if args.verbose {
-        println!("\nTotal collections found: {}", collection_names.len());
-        println!("Fetching details for each collection...\n");
-    }
-    
-    // Collect all collection information using map
-    let collections_info: Vec<CollectionInfo> = collection_names.iter().map(|name| {
-        let collection_url = format!("http://localhost:6333/collections/{}", name);
-        
-        let mut info = CollectionInfo {
-            name: name.clone(),
-            status: None,
-            vectors_count: None,
-            points_count: None,
-            indexed_vectors_count: None,
-            vector_config: None,
-            error: None,
-        };
-        
-        match client.get(&collection_url).send() {
-            Ok(coll_response) => {
-                if coll_response.status().is_success() {
-                    match coll_response.json::<Value>() {
-                        Ok(coll_data) => {
-                            // Extract useful information from the collection details
-                            if let Some(result) = coll_data.get("result") {
-                                info.status = result.get("status")
-                                    .and_then(|v| v.as_str())
-                                    .map(|s| s.to_string());
-                                
-                                info.vectors_count = result.get("vectors_count")
-                                    .and_then(|v| v.as_u64());
-                                
-                                info.points_count = result.get("points_count")
-                                    .and_then(|v| v.as_u64());
-                                
-                                info.indexed_vectors_count = result.get("indexed_vectors_count")
-                                    .and_then(|v| v.as_u64());
-                                
-                                if let Some(config) = result.get("config") {
-                                    if let Some(params) = config.get("params") {
-                                        info.vector_config = params.get("vectors").cloned();
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            info.error = Some(format!("Error parsing collection details: {}", e));
-                        }
-                    }
-                } else {
-                    info.error = Some(format!("Failed to get collection details (status: {})", coll_response.status()));
-                }
-            }
-            Err(e) => {
-                info.error = Some(format!("Failed to fetch collection details: {}", e));
+
+    info!(count = collection_names.len(), concurrency, "fetching collection details");
+
+    // Fetch per-collection details concurrently, bounded by a semaphore so we
+    // never have more than `concurrency` requests in flight at once.
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let fetches = collection_names.iter().map(|name| {
+        let semaphore = Arc::clone(&semaphore);
+        async move {
+            // The semaphore only has a fixed number of permits; while one is
+            // held this future runs, the rest wait their turn.
+            let _permit = semaphore.acquire().await.expect("semaphore is never closed");
+            fetch_collection(qdrant, name).await
+        }
+    });
+    let mut collections_info: Vec<CollectionInfo> = join_all(fetches).await;
+
+    // Drop empty collections when requested, keyed off points_count. Only a
+    // *known* zero count counts as empty: collections whose detail fetch failed
+    // (carrying an `error`, so `points_count` is `None`) are always kept so the
+    // monitoring path still sees them.
+    if exclude_empty {
+        collections_info.retain(|c| c.error.is_some() || c.points_count != Some(0));
+    }
+
+    Ok(collections_info)
+}
+
+/// Build a concise one-line health summary, e.g.
+/// `2/10 unhealthy: shards(red), docs(yellow)`.
+fn health_summary(collections: &[CollectionInfo], fail_on_yellow: bool) -> (usize, String) {
+    let failing: Vec<&CollectionInfo> = collections.iter().filter(|c| c.is_failure(fail_on_yellow)).collect();
+    if failing.is_empty() {
+        return (0, format!("all {} collections healthy", collections.len()));
+    }
+    let detail: Vec<String> = failing
+        .iter()
+        .map(|c| format!("{}({})", c.name, c.status.as_deref().unwrap_or("error")))
+        .collect();
+    (failing.len(), format!("{}/{} unhealthy: {}", failing.len(), collections.len(), detail.join(", ")))
+}
+
+/// Enumerate the collections that pass `query` and print their details,
+/// honouring `--only`; with `--check` or `--watch`, print a health summary
+/// line instead of the details.
+async fn run_list(qdrant: &Qdrant, output: OutputFormat, only: Option<&str>, concurrency: usize, query: CollectionsQuery, exclude_empty: bool, monitor: Monitor) -> Result<(), Box<dyn Error>> {
+    // Validate the --only argument if provided
+    if let Some(filter) = only {
+        if filter != "healthy" && filter != "unhealthy" {
+            return Err(format!("Invalid value for --only: '{}'. Must be 'healthy' or 'unhealthy'", filter).into());
+        }
+    }
+
+    // Watch mode: re-poll forever, emitting a timestamped status line each cycle.
+    if let Some(interval) = monitor.watch {
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(interval.max(1)));
+        loop {
+            ticker.tick().await;
+            let collections = collect_collections(qdrant, concurrency, &query, exclude_empty).await?;
+            let (unhealthy, summary) = health_summary(&collections, monitor.fail_on_yellow);
+            println!("[{}] {}", Local::now().format("%Y-%m-%dT%H:%M:%S"), summary);
+            if monitor.check && unhealthy > 0 {
+                std::process::exit(unhealthy.min(255) as i32);
             }
         }
-        
-        info
-    }).collect();
-    
+    }
+
+    let collections_info = collect_collections(qdrant, concurrency, &query, exclude_empty).await?;
+
+    // Check mode: print a one-line summary and exit by unhealthy count.
+    if monitor.check {
+        let (unhealthy, summary) = health_summary(&collections_info, monitor.fail_on_yellow);
+        println!("{}", summary);
+        std::process::exit(unhealthy.min(255) as i32);
+    }
+
     // Filter collections based on --only flag for display
-    let filtered_collections: Vec<&CollectionInfo> = match args.only.as_deref() {
+    let filtered_collections: Vec<&CollectionInfo> = match only {
         Some("healthy") => collections_info.iter().filter(|c| c.is_healthy()).collect(),
         Some("unhealthy") => collections_info.iter().filter(|c| !c.is_healthy()).collect(),
         _ => collections_info.iter().collect(),
     };
-    
-    // Print filtered information
-    if args.verbose {        
-        if let Some(filter) = &args.only {
-            println!("COLLECTION DETAILS (showing only {} collections)", filter);
-        } else {
-            println!("COLLECTION DETAILS");
-        }        
-    }
-    
-    println!("{}", serde_json::to_string_pretty(&filtered_collections)?);
-    
-    if args.verbose {
-        println!();        
-        println!("Displayed: {} / {} collections", filtered_collections.len(), collections_info.len());        
-    }
-    
+
+    // Emit the data on stdout in the requested format.
+    print_collections(&filtered_collections, output)?;
+
+    info!(displayed = filtered_collections.len(), total = collections_info.len(), "displayed collections");
+
+    Ok(())
+}
+
+/// Print the details of a single collection.
+async fn run_info(qdrant: &Qdrant, output: OutputFormat, name: &str) -> Result<(), Box<dyn Error>> {
+    let info = fetch_collection(qdrant, name).await;
+    print_collections(&[&info], output)?;
+    Ok(())
+}
+
+/// Create a new collection with the given vector size and distance metric.
+async fn run_create(qdrant: &Qdrant, name: &str, size: u64, distance: &str) -> Result<(), Box<dyn Error>> {
+    let path = format!("/collections/{}", name);
+    info!(collection = name, size, distance, "creating collection");
+
+    let payload = json!({
+        "vectors": {
+            "size": size,
+            "distance": distance,
+        }
+    });
+
+    let response = qdrant.put(&path).json(&payload).send().await?;
+    let status = response.status();
+    let body: Value = response.json().await?;
+
+    if !status.is_success() {
+        return Err(format!("Failed to create collection '{}' (status: {})", name, status).into());
+    }
+
+    println!("{}", serde_json::to_string_pretty(&body)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_substring_matches_and_misses() {
+        let query = CollectionsQuery::new(Some("docs".to_string()), vec![], None);
+        assert!(query.matches("prod-docs"));
+        assert!(!query.matches("prod-vectors"));
+    }
+
+    #[test]
+    fn id_allow_list_is_exact() {
+        let query = CollectionsQuery::new(None, vec!["docs".to_string(), "images".to_string()], None);
+        assert!(query.matches("docs"));
+        assert!(!query.matches("docs-archive"));
+    }
+
+    #[test]
+    fn regex_pattern_matches() {
+        let query = CollectionsQuery::new(None, vec![], Some(Regex::new(r"^prod-").unwrap()));
+        assert!(query.matches("prod-docs"));
+        assert!(!query.matches("staging-docs"));
+    }
+
+    #[test]
+    fn predicates_combine_with_and() {
+        let query = CollectionsQuery::new(
+            Some("docs".to_string()),
+            vec!["prod-docs".to_string()],
+            Some(Regex::new(r"^prod-").unwrap()),
+        );
+        // Satisfies every predicate.
+        assert!(query.matches("prod-docs"));
+        // Fails the regex even though the substring and id would pass alone.
+        let query = CollectionsQuery::new(
+            Some("docs".to_string()),
+            vec!["staging-docs".to_string()],
+            Some(Regex::new(r"^prod-").unwrap()),
+        );
+        assert!(!query.matches("staging-docs"));
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let query = CollectionsQuery::default();
+        assert!(query.matches("anything"));
+    }
+}
+
+/// Delete an existing collection.
+async fn run_delete(qdrant: &Qdrant, name: &str) -> Result<(), Box<dyn Error>> {
+    let path = format!("/collections/{}", name);
+    info!(collection = name, "deleting collection");
+
+    let response = qdrant.delete(&path).send().await?;
+    let status = response.status();
+    let body: Value = response.json().await?;
+
+    if !status.is_success() {
+        return Err(format!("Failed to delete collection '{}' (status: {})", name, status).into());
+    }
+
+    println!("{}", serde_json::to_string_pretty(&body)?);
+    Ok(())
+}
+
+/// Create a snapshot of a collection.
+async fn run_snapshot(qdrant: &Qdrant, name: &str) -> Result<(), Box<dyn Error>> {
+    let path = format!("/collections/{}/snapshots", name);
+    info!(collection = name, "creating snapshot");
+
+    let response = qdrant.post(&path).send().await?;
+    let status = response.status();
+    let body: Value = response.json().await?;
+
+    if !status.is_success() {
+        return Err(format!("Failed to snapshot collection '{}' (status: {})", name, status).into());
+    }
+
+    println!("{}", serde_json::to_string_pretty(&body)?);
     Ok(())
 }